@@ -1,14 +1,9 @@
-use regex::bytes::Regex;
-
-static FIELD_VALUE_WITH_OWS: &str = concat!(
-    r"^[ \t]*",
-    r"(",
-    r"[\x21-\x7e\x80-\xff]",                              // field-vchar
-    r"([ \t[\x21-\x7e\x80-\xff]]+[\x21-\x7e\x80-\xff])?", // [ 1*( SP / HTAB / field-vchar ) field-vchar ]
-    r")*",
-    r"[ \t]*$",
-);
+use std::borrow::Cow;
 
+// Mirrors the RFC 5234 core rules (all but the multi-line LWSP), not just
+// the subset the current parsers need, so a future ABNF rule can be
+// expressed in terms of it without growing the trait.
+#[allow(dead_code)]
 trait CharABNF {
     fn is_alpha(&self) -> bool;
     fn is_bit(&self) -> bool;
@@ -26,6 +21,7 @@ trait CharABNF {
     fn is_vchar(&self) -> bool;
     fn is_wsp(&self) -> bool;
     fn is_tchar(&self) -> bool;
+    fn is_field_vchar(&self) -> bool;
 }
 
 impl CharABNF for u8 {
@@ -111,6 +107,11 @@ impl CharABNF for u8 {
         // any VCHAR except delimiters
         self.is_alpha() || self.is_digit() || b"!#$%&'*+-.^_`|~".contains(self)
     }
+
+    fn is_field_vchar(&self) -> bool {
+        // field-vchar = VCHAR / obs-text
+        self.is_vchar() || *self >= 0x80
+    }
 }
 
 #[derive(Debug, PartialEq)]
@@ -119,6 +120,104 @@ pub enum ParseError {
     InvalidHeaderValueChar,
     ColonNotFound,
     InvalidHeaderValue,
+    InvalidMethod,
+    InvalidRequestTarget,
+    InvalidVersion,
+    InvalidStatusLine,
+    InvalidStatusCode,
+    InvalidReasonPhrase,
+    ObsFoldNotAllowed,
+    /// An obs-fold continuation line (RFC 7230) appeared with no preceding
+    /// header line for it to fold into.
+    ObsFoldWithoutPrecedingLine,
+}
+
+fn validate_version(version: &[u8]) -> Result<(), ParseError> {
+    // HTTP-version = "HTTP" "/" DIGIT "." DIGIT
+    if version.len() != 8
+        || &version[..5] != b"HTTP/"
+        || version[6] != b'.'
+        || !version[5].is_digit()
+        || !version[7].is_digit()
+    {
+        return Err(ParseError::InvalidVersion);
+    }
+
+    Ok(())
+}
+
+/// Method, request-target and HTTP-version, as subslices of the parsed line.
+type RequestLine<'a> = (&'a [u8], &'a [u8], &'a [u8]);
+
+/// Parses an HTTP request-line, e.g. `GET /index.html HTTP/1.1`, returning
+/// the method, request-target and HTTP-version as subslices of `line`.
+pub fn parse_request_line(line: &[u8]) -> Result<RequestLine<'_>, ParseError> {
+    let line = line.strip_suffix(b"\r\n").unwrap_or(line);
+    let line = line.strip_suffix(b"\n").unwrap_or(line);
+
+    let mut parts = line.splitn(3, |&c| c == b' ');
+    let method = parts
+        .next()
+        .filter(|m| !m.is_empty())
+        .ok_or(ParseError::InvalidMethod)?;
+    let target = parts
+        .next()
+        .filter(|t| !t.is_empty())
+        .ok_or(ParseError::InvalidRequestTarget)?;
+    let version = parts.next().ok_or(ParseError::InvalidVersion)?;
+
+    if !method.iter().all(|c| c.is_tchar()) {
+        return Err(ParseError::InvalidMethod);
+    }
+
+    if !target.iter().all(|c| c.is_vchar()) {
+        return Err(ParseError::InvalidRequestTarget);
+    }
+
+    validate_version(version)?;
+
+    Ok((method, target, version))
+}
+
+/// Parses an HTTP status-line, e.g. `HTTP/1.1 200 OK`, returning the
+/// HTTP-version, the 3-digit status code and the reason-phrase.
+pub fn parse_status_line(line: &[u8]) -> Result<(&[u8], u16, &[u8]), ParseError> {
+    let line = line.strip_suffix(b"\r\n").unwrap_or(line);
+    let line = line.strip_suffix(b"\n").unwrap_or(line);
+
+    if line.len() < 12 || line[8] != b' ' {
+        return Err(ParseError::InvalidStatusLine);
+    }
+
+    let version = &line[..8];
+    validate_version(version)?;
+
+    let status_bytes = &line[9..12];
+    if !status_bytes.iter().all(|c| c.is_digit()) {
+        return Err(ParseError::InvalidStatusCode);
+    }
+    let status_code = std::str::from_utf8(status_bytes)
+        .expect("already validated as ASCII digits")
+        .parse()
+        .expect("3 ASCII digits always fit in a u16");
+
+    let reason: &[u8] = if line.len() == 12 {
+        &line[12..]
+    } else {
+        if line[12] != b' ' {
+            return Err(ParseError::InvalidStatusLine);
+        }
+        &line[13..]
+    };
+
+    if !reason
+        .iter()
+        .all(|c| c.is_htab() || c.is_sp() || c.is_vchar() || *c >= 0x80)
+    {
+        return Err(ParseError::InvalidReasonPhrase);
+    }
+
+    Ok((version, status_code, reason))
 }
 
 pub fn extract_header_lines(headers: &[u8]) -> Vec<&[u8]> {
@@ -126,7 +225,7 @@ pub fn extract_header_lines(headers: &[u8]) -> Vec<&[u8]> {
 
     for line in headers
         .split(|&c| c == b'\n')
-        .filter(|&line| line.len() > 0)
+        .filter(|&line| !line.is_empty())
     {
         if line[line.len() - 1] == b'\r' {
             header_lines.push(&line[..line.len() - 1]);
@@ -138,7 +237,38 @@ pub fn extract_header_lines(headers: &[u8]) -> Vec<&[u8]> {
     header_lines
 }
 
+/// Like [`extract_header_lines`], but unfolds legacy obs-fold continuation
+/// lines (RFC 7230) instead of passing them through: a line beginning with
+/// SP or HTAB is appended to the previous line, with the CRLF and its
+/// leading whitespace replaced by a single SP. Returns owned lines since
+/// unfolding requires joining bytes from two physical lines. Errors with
+/// [`ParseError::ObsFoldWithoutPrecedingLine`] if the very first line is
+/// itself a continuation, since there is nothing for it to fold into.
+pub fn extract_header_lines_unfolded(headers: &[u8]) -> Result<Vec<Vec<u8>>, ParseError> {
+    let mut unfolded: Vec<Vec<u8>> = Vec::new();
+
+    for line in extract_header_lines(headers) {
+        match line.first() {
+            Some(c) if c.is_wsp() => {
+                let last = unfolded
+                    .last_mut()
+                    .ok_or(ParseError::ObsFoldWithoutPrecedingLine)?;
+                let start = line.iter().position(|c| !c.is_wsp()).unwrap_or(line.len());
+                last.push(b' ');
+                last.extend_from_slice(&line[start..]);
+            }
+            _ => unfolded.push(line.to_vec()),
+        }
+    }
+
+    Ok(unfolded)
+}
+
 pub fn extract_header_name_value(header_line: &[u8]) -> Result<(&[u8], &[u8]), ParseError> {
+    if header_line.first().is_some_and(|c| c.is_wsp()) {
+        return Err(ParseError::ObsFoldNotAllowed);
+    }
+
     let mut colon_index: Option<usize> = None;
 
     for (i, &c) in header_line.iter().enumerate() {
@@ -154,22 +284,574 @@ pub fn extract_header_name_value(header_line: &[u8]) -> Result<(&[u8], &[u8]), P
 
     let colon_index = colon_index.ok_or(ParseError::ColonNotFound)?;
 
-    let re = Regex::new(FIELD_VALUE_WITH_OWS).expect("Invalid regex");
-    let haystack = &header_line[colon_index + 1..];
-
-    let capture = re
-        .captures(haystack)
-        .ok_or(ParseError::InvalidHeaderValue)?;
-
     let key = &header_line[..colon_index];
-    let value = capture
-        .get(1)
-        .ok_or(ParseError::InvalidHeaderValue)?
-        .as_bytes();
+    let value = trim_and_validate_field_value(&header_line[colon_index + 1..])?;
 
     Ok((key, value))
 }
 
+/// Trims leading and trailing OWS (SP/HTAB) from `bytes`.
+fn trim_wsp(bytes: &[u8]) -> &[u8] {
+    let start = bytes
+        .iter()
+        .position(|c| !c.is_wsp())
+        .unwrap_or(bytes.len());
+    let end = bytes
+        .iter()
+        .rposition(|c| !c.is_wsp())
+        .map_or(start, |i| i + 1);
+
+    &bytes[start..end]
+}
+
+/// Trims leading/trailing OWS (SP/HTAB) from `haystack` and validates the
+/// remaining interior as `field-vchar (SP/HTAB/field-vchar)*`, returning a
+/// zero-copy subslice. Replaces a per-call regex compilation with a single
+/// linear pass, using a runtime-detected SIMD fast path where available.
+fn trim_and_validate_field_value(haystack: &[u8]) -> Result<&[u8], ParseError> {
+    let value = trim_wsp(haystack);
+
+    if value.is_empty() {
+        return Err(ParseError::InvalidHeaderValue);
+    }
+
+    let mut i = 0;
+    while i < value.len() {
+        i += first_non_field_vchar(&value[i..]);
+        if i == value.len() {
+            break;
+        }
+        if !value[i].is_wsp() {
+            return Err(ParseError::InvalidHeaderValue);
+        }
+        i += 1;
+    }
+
+    Ok(value)
+}
+
+/// A parameter's `name`, and its `value` — borrowed for a bare token, owned
+/// only when a `quoted-string` needed `quoted-pair` unescaping.
+type Parameter<'a> = (&'a [u8], Cow<'a, [u8]>);
+
+/// Parses a `token *( OWS ";" OWS parameter )` header value (as used by
+/// `Content-Type`, `Content-Disposition`, `Cache-Control`, etc.), splitting
+/// on `;` that isn't inside a quoted-string. Each `parameter` is a
+/// `name=value` pair; `value` is either a bare token (validated with
+/// `is_tchar`) or a `quoted-string`, whose surrounding `DQUOTE`s are
+/// stripped and whose `quoted-pair` escapes (`\` + char) are unescaped.
+pub fn parse_parameters(value: &[u8]) -> Result<(&[u8], Vec<Parameter<'_>>), ParseError> {
+    let mut segments = split_unquoted(value, b';').into_iter();
+
+    // `type "/" subtype`, same as the bare-token parameter values below —
+    // `/` is the one non-tchar byte real media-types need (e.g.
+    // `text/html`), so it's allowed alongside `is_tchar` rather than
+    // widening the check to arbitrary field-vchar/OWS.
+    let main = trim_wsp(segments.next().unwrap_or(value));
+    if main.is_empty() || !main.iter().all(|c| c.is_tchar() || *c == b'/') {
+        return Err(ParseError::InvalidHeaderValue);
+    }
+    let mut params = Vec::new();
+
+    for segment in segments {
+        let segment = trim_wsp(segment);
+
+        let eq_index = segment
+            .iter()
+            .position(|&c| c == b'=')
+            .ok_or(ParseError::InvalidHeaderValue)?;
+
+        let name = trim_wsp(&segment[..eq_index]);
+        if name.is_empty() || !name.iter().all(|c| c.is_tchar()) {
+            return Err(ParseError::InvalidHeaderValue);
+        }
+
+        let raw_value = trim_wsp(&segment[eq_index + 1..]);
+        let value = parse_parameter_value(raw_value)?;
+
+        params.push((name, value));
+    }
+
+    Ok((main, params))
+}
+
+/// Splits `haystack` on `delim` bytes that are not inside a quoted-string,
+/// so a delimiter quoted inside e.g. `boundary="a;b"` doesn't split it.
+fn split_unquoted(haystack: &[u8], delim: u8) -> Vec<&[u8]> {
+    let mut segments = Vec::new();
+    let mut start = 0;
+    let mut in_quotes = false;
+    let mut i = 0;
+
+    while i < haystack.len() {
+        match haystack[i] {
+            b'"' => in_quotes = !in_quotes,
+            b'\\' if in_quotes => i += 1, // skip the escaped char of a quoted-pair
+            c if c == delim && !in_quotes => {
+                segments.push(&haystack[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+    segments.push(&haystack[start..]);
+
+    segments
+}
+
+fn parse_parameter_value(raw_value: &[u8]) -> Result<Cow<'_, [u8]>, ParseError> {
+    if raw_value.first() == Some(&b'"') {
+        return parse_quoted_string(raw_value);
+    }
+
+    if raw_value.is_empty() || !raw_value.iter().all(|c| c.is_tchar()) {
+        return Err(ParseError::InvalidHeaderValue);
+    }
+
+    Ok(Cow::Borrowed(raw_value))
+}
+
+fn is_qdtext(c: u8) -> bool {
+    // qdtext = HTAB / SP / %x21 / %x23-5B / %x5D-7E / obs-text
+    c.is_htab() || c.is_sp() || (c.is_vchar() && c != b'"' && c != b'\\') || c >= 0x80
+}
+
+fn parse_quoted_string(raw: &[u8]) -> Result<Cow<'_, [u8]>, ParseError> {
+    if raw.len() < 2 || raw[0] != b'"' || raw[raw.len() - 1] != b'"' {
+        return Err(ParseError::InvalidHeaderValue);
+    }
+
+    let interior = &raw[1..raw.len() - 1];
+
+    if !interior.contains(&b'\\') {
+        if !interior.iter().all(|&c| is_qdtext(c)) {
+            return Err(ParseError::InvalidHeaderValue);
+        }
+        return Ok(Cow::Borrowed(interior));
+    }
+
+    let mut unescaped = Vec::with_capacity(interior.len());
+    let mut i = 0;
+
+    while i < interior.len() {
+        if interior[i] == b'\\' {
+            let escaped = *interior.get(i + 1).ok_or(ParseError::InvalidHeaderValue)?;
+
+            if !(escaped.is_htab() || escaped.is_sp() || escaped.is_vchar() || escaped >= 0x80) {
+                return Err(ParseError::InvalidHeaderValue);
+            }
+
+            unescaped.push(escaped);
+            i += 2;
+        } else {
+            if !is_qdtext(interior[i]) {
+                return Err(ParseError::InvalidHeaderValue);
+            }
+            unescaped.push(interior[i]);
+            i += 1;
+        }
+    }
+
+    Ok(Cow::Owned(unescaped))
+}
+
+/// Returns the index of the first byte in `bytes` that is not a field-vchar
+/// (VCHAR / obs-text), or `bytes.len()` if every byte qualifies. Dispatches
+/// to a SIMD implementation at runtime when the target CPU supports one,
+/// mirroring httparse's approach of scanning many bytes per instruction and
+/// falling back to a scalar loop on unsupported targets.
+fn first_non_field_vchar(bytes: &[u8]) -> usize {
+    #[cfg(target_arch = "x86_64")]
+    {
+        if std::is_x86_feature_detected!("avx2") {
+            return unsafe { simd::first_non_field_vchar_avx2(bytes) };
+        }
+        if std::is_x86_feature_detected!("sse4.2") {
+            return unsafe { simd::first_non_field_vchar_sse42(bytes) };
+        }
+    }
+
+    first_non_field_vchar_scalar(bytes)
+}
+
+fn first_non_field_vchar_scalar(bytes: &[u8]) -> usize {
+    bytes
+        .iter()
+        .position(|c| !c.is_field_vchar())
+        .unwrap_or(bytes.len())
+}
+
+#[cfg(target_arch = "x86_64")]
+mod simd {
+    use super::first_non_field_vchar_scalar;
+    use std::arch::x86_64::*;
+
+    /// Scans 32 bytes at a time for the first byte outside field-vchar
+    /// (0x21-0x7e, or 0x80-0xff for obs-text).
+    #[target_feature(enable = "avx2")]
+    pub(super) unsafe fn first_non_field_vchar_avx2(bytes: &[u8]) -> usize {
+        let mut i = 0;
+
+        while i + 32 <= bytes.len() {
+            let chunk = unsafe { _mm256_loadu_si256(bytes.as_ptr().add(i) as *const __m256i) };
+
+            // obs-text bytes (0x80-0xff) are always valid; as i8 they're
+            // negative, so the high bit of the movemask marks them.
+            let is_obs_text = _mm256_movemask_epi8(chunk) as u32;
+
+            // Within 0x00-0x7f, valid iff 0x21 <= byte <= 0x7e.
+            let below = _mm256_cmpgt_epi8(_mm256_set1_epi8(0x21), chunk);
+            let above = _mm256_cmpgt_epi8(chunk, _mm256_set1_epi8(0x7e));
+            let invalid_ascii = _mm256_movemask_epi8(_mm256_or_si256(below, above)) as u32;
+
+            let invalid = invalid_ascii & !is_obs_text;
+            if invalid != 0 {
+                return i + invalid.trailing_zeros() as usize;
+            }
+
+            i += 32;
+        }
+
+        i + first_non_field_vchar_scalar(&bytes[i..])
+    }
+
+    /// Scans 16 bytes at a time for the first byte outside field-vchar.
+    #[target_feature(enable = "sse4.2")]
+    pub(super) unsafe fn first_non_field_vchar_sse42(bytes: &[u8]) -> usize {
+        let mut i = 0;
+
+        while i + 16 <= bytes.len() {
+            let chunk = unsafe { _mm_loadu_si128(bytes.as_ptr().add(i) as *const __m128i) };
+
+            // Range table for PCMPISTRI: each (low, high) pair marks an
+            // inclusive byte range that counts as "in range" for the
+            // negated-polarity range comparison below.
+            let ranges = _mm_setr_epi8(
+                0x21,
+                0x7e, // field-vchar
+                0x80u8 as i8,
+                0xffu8 as i8, // obs-text
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+            );
+
+            let idx = _mm_cmpistri(
+                ranges,
+                chunk,
+                _SIDD_UBYTE_OPS | _SIDD_CMP_RANGES | _SIDD_NEGATIVE_POLARITY,
+            );
+
+            if idx != 16 {
+                return i + idx as usize;
+            }
+
+            i += 16;
+        }
+
+        i + first_non_field_vchar_scalar(&bytes[i..])
+    }
+}
+
+/// The result of an incremental (push-style) parse: either the input was
+/// fully recognized, or more bytes are needed before it can be.
+#[derive(Debug, PartialEq)]
+pub enum Status<T> {
+    Complete(T),
+    Partial,
+}
+
+/// The header name/value pairs and consumed-byte count returned by
+/// [`parse_headers`] on a complete parse.
+type ParsedHeaders<'a> = (Vec<(&'a [u8], &'a [u8])>, usize);
+
+/// Scans `buf` for the empty line that terminates an HTTP header section
+/// (`\r\n\r\n` or `\n\n`), modeled on httparse's `Status::{Complete, Partial}`
+/// so a caller reading off a socket can tell "need more bytes" apart from
+/// "malformed". On `Complete`, returns the parsed `(name, value)` pairs
+/// together with the number of bytes consumed, so the caller can advance
+/// its buffer without re-scanning the headers it has already parsed.
+pub fn parse_headers(buf: &[u8]) -> Result<Status<ParsedHeaders<'_>>, ParseError> {
+    let (header_section_end, consumed) = match find_headers_terminator(buf) {
+        Some(bounds) => bounds,
+        None => return Ok(Status::Partial),
+    };
+
+    let mut headers = Vec::new();
+    for line in extract_header_lines(&buf[..header_section_end]) {
+        headers.push(extract_header_name_value(line)?);
+    }
+
+    Ok(Status::Complete((headers, consumed)))
+}
+
+/// Looks for the blank line that ends a header section and, if found,
+/// returns `(header_section_end, consumed)`: the former is the offset just
+/// past the last real header line (for splitting into header lines), the
+/// latter is the offset just past the blank line itself (for advancing the
+/// caller's buffer past what's now been parsed).
+fn find_headers_terminator(buf: &[u8]) -> Option<(usize, usize)> {
+    // A header section with zero headers starts with the terminator itself,
+    // with no preceding line's `\n` to scan for.
+    if buf.starts_with(b"\r\n") {
+        return Some((0, 2));
+    }
+    if buf.starts_with(b"\n") {
+        return Some((0, 1));
+    }
+
+    for i in 0..buf.len() {
+        if buf[i] != b'\n' {
+            continue;
+        }
+
+        if buf.get(i + 1) == Some(&b'\n') {
+            return Some((i + 1, i + 2));
+        }
+
+        if buf.get(i + 1) == Some(&b'\r') && buf.get(i + 2) == Some(&b'\n') {
+            return Some((i + 1, i + 3));
+        }
+    }
+
+    None
+}
+
+/// Charset-aware decoding of header values (RFC 2047 encoded-words and
+/// RFC 8187 ext-values), kept behind the `encoding` feature so the core
+/// parser stays dependency-light for callers who only need raw bytes.
+#[cfg(feature = "encoding")]
+mod encoding {
+    use std::borrow::Cow;
+
+    use super::CharABNF;
+
+    /// Decodes a header value that may contain RFC 2047 encoded-words
+    /// (`=?charset?B|Q?text?=`) or an RFC 8187 ext-value
+    /// (`charset'lang'pct-encoded`), transcoding from the named charset to
+    /// UTF-8 via `encoding_rs`. A value with no ext-value and no `=?` that is
+    /// valid UTF-8 is returned borrowed; invalid UTF-8 is replaced lossily,
+    /// and anything decoded is copied into an owned `String`. As
+    /// required by RFC 2047, whitespace between two adjacent encoded-words
+    /// is stripped rather than preserved.
+    pub fn decode_value(value: &[u8]) -> Cow<'_, str> {
+        if let Some(decoded) = decode_ext_value(value) {
+            return Cow::Owned(decoded);
+        }
+
+        if !value.windows(2).any(|w| w == b"=?") {
+            return String::from_utf8_lossy(value);
+        }
+
+        Cow::Owned(decode_encoded_words(value))
+    }
+
+    fn decode_encoded_words(value: &[u8]) -> String {
+        let mut out = String::new();
+        let mut rest = value;
+        let mut last_was_encoded_word = false;
+
+        while !rest.is_empty() {
+            match find_encoded_word(rest) {
+                Some((gap, decoded, after)) => {
+                    let gap_is_only_wsp = gap.iter().all(|c| c.is_wsp());
+
+                    if !(last_was_encoded_word && gap_is_only_wsp) {
+                        out.push_str(&String::from_utf8_lossy(gap));
+                    }
+
+                    out.push_str(&decoded);
+                    last_was_encoded_word = true;
+                    rest = after;
+                }
+                None => {
+                    out.push_str(&String::from_utf8_lossy(rest));
+                    break;
+                }
+            }
+        }
+
+        out
+    }
+
+    /// Finds the next `=?charset?B|Q?text?=` token in `haystack`, returning
+    /// the bytes before it, its decoded text, and the bytes after it.
+    fn find_encoded_word(haystack: &[u8]) -> Option<(&[u8], String, &[u8])> {
+        let start = find_subslice(haystack, b"=?")?;
+        let rest = &haystack[start + 2..];
+
+        let charset_end = find_subslice(rest, b"?")?;
+        let charset = &rest[..charset_end];
+        let rest = &rest[charset_end + 1..];
+
+        if rest.len() < 2 || rest[1] != b'?' {
+            return None;
+        }
+        let encoding = rest[0];
+        let rest = &rest[2..];
+
+        let text_end = find_subslice(rest, b"?=")?;
+        let text = &rest[..text_end];
+        let after = &rest[text_end + 2..];
+
+        let raw = match encoding.to_ascii_uppercase() {
+            b'B' => base64_decode(text)?,
+            b'Q' => quoted_printable_decode(text),
+            _ => return None,
+        };
+
+        let decoded = transcode(charset, &raw)?;
+
+        Some((&haystack[..start], decoded, after))
+    }
+
+    fn find_subslice(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+        if needle.is_empty() || haystack.len() < needle.len() {
+            return None;
+        }
+        haystack.windows(needle.len()).position(|w| w == needle)
+    }
+
+    fn transcode(charset: &[u8], bytes: &[u8]) -> Option<String> {
+        let label = std::str::from_utf8(charset).ok()?;
+        let encoding = encoding_rs::Encoding::for_label(label.as_bytes())?;
+        let (decoded, _, had_errors) = encoding.decode(bytes);
+
+        if had_errors {
+            return None;
+        }
+
+        Some(decoded.into_owned())
+    }
+
+    fn base64_decode(text: &[u8]) -> Option<Vec<u8>> {
+        fn value(b: u8) -> Option<u8> {
+            match b {
+                b'A'..=b'Z' => Some(b - b'A'),
+                b'a'..=b'z' => Some(b - b'a' + 26),
+                b'0'..=b'9' => Some(b - b'0' + 52),
+                b'+' => Some(62),
+                b'/' => Some(63),
+                _ => None,
+            }
+        }
+
+        let mut out = Vec::with_capacity(text.len() * 3 / 4 + 3);
+        let mut buffer: u32 = 0;
+        let mut bits: u32 = 0;
+
+        for &b in text.iter().filter(|&&b| b != b'=') {
+            buffer = (buffer << 6) | value(b)? as u32;
+            bits += 6;
+            if bits >= 8 {
+                bits -= 8;
+                out.push((buffer >> bits) as u8);
+            }
+        }
+
+        Some(out)
+    }
+
+    fn quoted_printable_decode(text: &[u8]) -> Vec<u8> {
+        let mut out = Vec::with_capacity(text.len());
+        let mut i = 0;
+
+        while i < text.len() {
+            match text[i] {
+                b'_' => {
+                    out.push(b' ');
+                    i += 1;
+                }
+                b'=' => match hex_byte(text.get(i + 1..i + 3)) {
+                    Some(byte) => {
+                        out.push(byte);
+                        i += 3;
+                    }
+                    None => {
+                        out.push(text[i]);
+                        i += 1;
+                    }
+                },
+                c => {
+                    out.push(c);
+                    i += 1;
+                }
+            }
+        }
+
+        out
+    }
+
+    fn hex_byte(hex: Option<&[u8]>) -> Option<u8> {
+        let hex = hex?;
+        if hex.len() != 2 {
+            return None;
+        }
+        let hi = (hex[0] as char).to_digit(16)?;
+        let lo = (hex[1] as char).to_digit(16)?;
+        Some(((hi << 4) | lo) as u8)
+    }
+
+    /// RFC 8187: `charset'lang'pct-encoded`. Only attempted when the value
+    /// has both required quotes and at least one `%`, so plain text with a
+    /// stray apostrophe is left alone.
+    fn decode_ext_value(value: &[u8]) -> Option<String> {
+        let first_quote = value.iter().position(|&c| c == b'\'')?;
+        let charset = &value[..first_quote];
+
+        if charset.is_empty() || !charset.iter().all(|c| c.is_tchar()) {
+            return None;
+        }
+
+        let rest = &value[first_quote + 1..];
+        let second_quote = rest.iter().position(|&c| c == b'\'')?;
+        let rest = &rest[second_quote + 1..];
+
+        if !rest.contains(&b'%') {
+            return None;
+        }
+
+        transcode(charset, &percent_decode(rest))
+    }
+
+    fn percent_decode(bytes: &[u8]) -> Vec<u8> {
+        let mut out = Vec::with_capacity(bytes.len());
+        let mut i = 0;
+
+        while i < bytes.len() {
+            let escape = (bytes[i] == b'%')
+                .then(|| hex_byte(bytes.get(i + 1..i + 3)))
+                .flatten();
+
+            match escape {
+                Some(byte) => {
+                    out.push(byte);
+                    i += 3;
+                }
+                None => {
+                    out.push(bytes[i]);
+                    i += 1;
+                }
+            }
+        }
+
+        out
+    }
+}
+
+#[cfg(feature = "encoding")]
+pub use encoding::decode_value;
+
 #[cfg(test)]
 mod tests {
 
@@ -197,6 +879,57 @@ mod tests {
         assert_eq!(header_lines[1], b"Content-Length: 1234");
     }
 
+    #[test]
+    fn extract_header_lines_unfolded_joins_continuation_line() {
+        let headers = b"Subject: hello\r\n world\r\nContent-Length: 5\r\n";
+
+        let header_lines = extract_header_lines_unfolded(headers).unwrap();
+
+        assert_eq!(header_lines.len(), 2);
+        assert_eq!(header_lines[0], b"Subject: hello world");
+        assert_eq!(header_lines[1], b"Content-Length: 5");
+    }
+
+    #[test]
+    fn extract_header_lines_unfolded_joins_multiple_continuation_lines() {
+        let headers = b"Subject: hello\r\n world\r\n\tagain\r\n";
+
+        let header_lines = extract_header_lines_unfolded(headers).unwrap();
+
+        assert_eq!(header_lines.len(), 1);
+        assert_eq!(header_lines[0], b"Subject: hello world again");
+    }
+
+    #[test]
+    fn extract_header_lines_unfolded_leaves_unfolded_headers_untouched() {
+        let headers = b"Content-Type: text/html\r\nContent-Length: 1234\r\n";
+
+        let header_lines = extract_header_lines_unfolded(headers).unwrap();
+
+        assert_eq!(header_lines.len(), 2);
+        assert_eq!(header_lines[0], b"Content-Type: text/html");
+        assert_eq!(header_lines[1], b"Content-Length: 1234");
+    }
+
+    #[test]
+    fn extract_header_lines_unfolded_rejects_leading_continuation_line() {
+        let headers = b" x-foo: bar\r\n";
+
+        let result = extract_header_lines_unfolded(headers);
+
+        assert_eq!(result.unwrap_err(), ParseError::ObsFoldWithoutPrecedingLine);
+    }
+
+    #[test]
+    fn extract_header_name_value_rejects_obs_fold() {
+        let header_line = b" folded continuation";
+
+        let result = extract_header_name_value(header_line);
+
+        assert!(result.is_err());
+        assert_eq!(result.unwrap_err(), ParseError::ObsFoldNotAllowed);
+    }
+
     #[test]
     fn extract_key_value_from_headerline_without_ows() {
         let header_line = b"Content-Type:text/html";
@@ -257,6 +990,242 @@ mod tests {
         assert_eq!(result.unwrap_err(), ParseError::InvalidHeaderValue);
     }
 
+    /// Byte strings covering the chunk-boundary and mid-chunk cases the
+    /// AVX2 (32-byte) and SSE4.2 (16-byte) kernels scan. Each kernel is
+    /// checked directly against the scalar loop, not only the one the
+    /// runtime dispatch picks; a kernel whose CPU feature the host lacks is
+    /// not checked.
+    #[cfg(target_arch = "x86_64")]
+    fn first_non_field_vchar_test_cases() -> Vec<Vec<u8>> {
+        let mut cases = vec![Vec::new(), b"short".to_vec()];
+
+        for len in [1usize, 15, 16, 17, 31, 32, 33, 63, 64, 65] {
+            cases.push(vec![b'a'; len]);
+
+            for pos in 0..len {
+                let mut invalid = vec![b'a'; len];
+                invalid[pos] = 0x00; // NUL: not a field-vchar
+                cases.push(invalid);
+
+                let mut obs_text = vec![b'a'; len];
+                obs_text[pos] = 0x80; // obs-text: a valid field-vchar
+                cases.push(obs_text);
+            }
+        }
+
+        cases
+    }
+
+    #[cfg(target_arch = "x86_64")]
+    #[test]
+    fn first_non_field_vchar_avx2_matches_scalar() {
+        if !std::is_x86_feature_detected!("avx2") {
+            return;
+        }
+
+        for case in first_non_field_vchar_test_cases() {
+            let expected = first_non_field_vchar_scalar(&case);
+            let actual = unsafe { super::simd::first_non_field_vchar_avx2(&case) };
+            assert_eq!(actual, expected, "case: {case:?}");
+        }
+    }
+
+    #[cfg(target_arch = "x86_64")]
+    #[test]
+    fn first_non_field_vchar_sse42_matches_scalar() {
+        if !std::is_x86_feature_detected!("sse4.2") {
+            return;
+        }
+
+        for case in first_non_field_vchar_test_cases() {
+            let expected = first_non_field_vchar_scalar(&case);
+            let actual = unsafe { super::simd::first_non_field_vchar_sse42(&case) };
+            assert_eq!(actual, expected, "case: {case:?}");
+        }
+    }
+
+    #[test]
+    fn parse_request_line_with_crlf() {
+        let line = b"GET /index.html HTTP/1.1\r\n";
+
+        let (method, target, version) = parse_request_line(line).unwrap();
+
+        assert_eq!(method, b"GET");
+        assert_eq!(target, b"/index.html");
+        assert_eq!(version, b"HTTP/1.1");
+    }
+
+    #[test]
+    fn parse_request_line_without_crlf() {
+        let line = b"POST / HTTP/1.0";
+
+        let (method, target, version) = parse_request_line(line).unwrap();
+
+        assert_eq!(method, b"POST");
+        assert_eq!(target, b"/");
+        assert_eq!(version, b"HTTP/1.0");
+    }
+
+    #[test]
+    fn parse_request_line_with_invalid_method() {
+        let line = b"G=T / HTTP/1.1";
+
+        let result = parse_request_line(line);
+
+        assert!(result.is_err());
+        assert_eq!(result.unwrap_err(), ParseError::InvalidMethod);
+    }
+
+    #[test]
+    fn parse_request_line_with_invalid_version() {
+        let line = b"GET / HTTP/11";
+
+        let result = parse_request_line(line);
+
+        assert!(result.is_err());
+        assert_eq!(result.unwrap_err(), ParseError::InvalidVersion);
+    }
+
+    #[test]
+    fn parse_request_line_missing_parts() {
+        let line = b"GET /index.html";
+
+        let result = parse_request_line(line);
+
+        assert!(result.is_err());
+        assert_eq!(result.unwrap_err(), ParseError::InvalidVersion);
+    }
+
+    #[test]
+    fn parse_status_line_with_reason_phrase() {
+        let line = b"HTTP/1.1 200 OK\r\n";
+
+        let (version, status_code, reason) = parse_status_line(line).unwrap();
+
+        assert_eq!(version, b"HTTP/1.1");
+        assert_eq!(status_code, 200);
+        assert_eq!(reason, b"OK");
+    }
+
+    #[test]
+    fn parse_status_line_without_reason_phrase() {
+        let line = b"HTTP/1.1 204";
+
+        let (version, status_code, reason) = parse_status_line(line).unwrap();
+
+        assert_eq!(version, b"HTTP/1.1");
+        assert_eq!(status_code, 204);
+        assert_eq!(reason, b"");
+    }
+
+    #[test]
+    fn parse_status_line_with_invalid_status_code() {
+        let line = b"HTTP/1.1 2OK OK";
+
+        let result = parse_status_line(line);
+
+        assert!(result.is_err());
+        assert_eq!(result.unwrap_err(), ParseError::InvalidStatusCode);
+    }
+
+    #[test]
+    fn parse_status_line_with_invalid_version() {
+        let line = b"HTTP/1.x 200 OK";
+
+        let result = parse_status_line(line);
+
+        assert!(result.is_err());
+        assert_eq!(result.unwrap_err(), ParseError::InvalidVersion);
+    }
+
+    #[test]
+    fn parse_headers_complete_with_crlf() {
+        let buf = b"Content-Type: text/html\r\nContent-Length: 1234\r\n\r\n";
+
+        let status = parse_headers(buf).unwrap();
+
+        match status {
+            Status::Complete((headers, consumed)) => {
+                assert_eq!(
+                    headers,
+                    vec![
+                        (b"Content-Type".as_slice(), b"text/html".as_slice()),
+                        (b"Content-Length".as_slice(), b"1234".as_slice()),
+                    ]
+                );
+                assert_eq!(consumed, buf.len());
+            }
+            Status::Partial => panic!("expected Status::Complete"),
+        }
+    }
+
+    #[test]
+    fn parse_headers_complete_with_lf_only() {
+        let buf = b"Content-Type: text/html\nContent-Length: 1234\n\n";
+
+        let status = parse_headers(buf).unwrap();
+
+        match status {
+            Status::Complete((headers, consumed)) => {
+                assert_eq!(
+                    headers,
+                    vec![
+                        (b"Content-Type".as_slice(), b"text/html".as_slice()),
+                        (b"Content-Length".as_slice(), b"1234".as_slice()),
+                    ]
+                );
+                assert_eq!(consumed, buf.len());
+            }
+            Status::Partial => panic!("expected Status::Complete"),
+        }
+    }
+
+    #[test]
+    fn parse_headers_complete_with_no_headers() {
+        assert_eq!(
+            parse_headers(b"\r\n").unwrap(),
+            Status::Complete((vec![], 2))
+        );
+        assert_eq!(parse_headers(b"\n").unwrap(), Status::Complete((vec![], 1)));
+    }
+
+    #[test]
+    fn parse_headers_partial_without_terminator() {
+        let buf = b"Content-Type: text/html\r\nContent-Len";
+
+        let status = parse_headers(buf).unwrap();
+
+        assert_eq!(status, Status::Partial);
+    }
+
+    #[test]
+    fn parse_headers_resumes_without_reparsing_completed_bytes() {
+        let buf = b"Content-Type: text/html\r\n\r\n<html>";
+
+        let status = parse_headers(buf).unwrap();
+
+        let (headers, consumed) = match status {
+            Status::Complete(result) => result,
+            Status::Partial => panic!("expected Status::Complete"),
+        };
+
+        assert_eq!(
+            headers,
+            vec![(b"Content-Type".as_slice(), b"text/html".as_slice())]
+        );
+        assert_eq!(&buf[consumed..], b"<html>");
+    }
+
+    #[test]
+    fn parse_headers_propagates_malformed_header_error() {
+        let buf = b"Content-Type\r\n\r\n";
+
+        let result = parse_headers(buf);
+
+        assert!(result.is_err());
+        assert_eq!(result.unwrap_err(), ParseError::ColonNotFound);
+    }
+
     #[test]
     fn extract_key_value_from_user_agent_line() {
         let header_line = b"User-Agent: Mozilla/5.0 (Windows NT 6.1; Win64; x64; rv:47.0) Gecko/20100101 Firefox/47.0";
@@ -269,4 +1238,158 @@ mod tests {
             b"Mozilla/5.0 (Windows NT 6.1; Win64; x64; rv:47.0) Gecko/20100101 Firefox/47.0"
         );
     }
+
+    #[test]
+    fn parse_parameters_with_bare_tokens() {
+        let value = b"text/html; charset=utf-8";
+
+        let (main, params) = parse_parameters(value).unwrap();
+
+        assert_eq!(main, b"text/html");
+        assert_eq!(
+            params,
+            vec![(b"charset".as_slice(), Cow::Borrowed(b"utf-8".as_slice()))]
+        );
+    }
+
+    #[test]
+    fn parse_parameters_with_quoted_value() {
+        let value = br#"multipart/form-data; boundary="some;boundary""#;
+
+        let (main, params) = parse_parameters(value).unwrap();
+
+        assert_eq!(main, b"multipart/form-data");
+        assert_eq!(
+            params,
+            vec![(
+                b"boundary".as_slice(),
+                Cow::Borrowed(b"some;boundary".as_slice())
+            )]
+        );
+    }
+
+    #[test]
+    fn parse_parameters_unescapes_quoted_pairs() {
+        let value = br#"attachment; filename="quote \" and backslash \\""#;
+
+        let (main, params) = parse_parameters(value).unwrap();
+
+        assert_eq!(main, b"attachment");
+        assert_eq!(
+            params,
+            vec![(
+                b"filename".as_slice(),
+                Cow::<[u8]>::Owned(b"quote \" and backslash \\".to_vec())
+            )]
+        );
+    }
+
+    #[test]
+    fn parse_parameters_with_multiple_params() {
+        let value = b"text/html; charset=utf-8; boundary=xyz";
+
+        let (main, params) = parse_parameters(value).unwrap();
+
+        assert_eq!(main, b"text/html");
+        assert_eq!(
+            params,
+            vec![
+                (b"charset".as_slice(), Cow::Borrowed(b"utf-8".as_slice())),
+                (b"boundary".as_slice(), Cow::Borrowed(b"xyz".as_slice())),
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_parameters_rejects_missing_equals() {
+        let value = b"text/html; charset";
+
+        let result = parse_parameters(value);
+
+        assert!(result.is_err());
+        assert_eq!(result.unwrap_err(), ParseError::InvalidHeaderValue);
+    }
+
+    #[test]
+    fn parse_parameters_rejects_unterminated_quoted_string() {
+        let value = br#"attachment; filename="unterminated"#;
+
+        let result = parse_parameters(value);
+
+        assert!(result.is_err());
+        assert_eq!(result.unwrap_err(), ParseError::InvalidHeaderValue);
+    }
+
+    #[test]
+    fn parse_parameters_rejects_empty_main() {
+        let value = b"; charset=utf-8";
+
+        let result = parse_parameters(value);
+
+        assert!(result.is_err());
+        assert_eq!(result.unwrap_err(), ParseError::InvalidHeaderValue);
+    }
+
+    #[test]
+    fn parse_parameters_rejects_invalid_byte_in_main() {
+        let value = b"text/ht\x01ml; charset=utf-8";
+
+        let result = parse_parameters(value);
+
+        assert!(result.is_err());
+        assert_eq!(result.unwrap_err(), ParseError::InvalidHeaderValue);
+    }
+
+    #[test]
+    fn parse_parameters_rejects_embedded_whitespace_in_main() {
+        let value = b"text /html; charset=utf-8";
+
+        let result = parse_parameters(value);
+
+        assert!(result.is_err());
+        assert_eq!(result.unwrap_err(), ParseError::InvalidHeaderValue);
+    }
+
+    #[cfg(feature = "encoding")]
+    #[test]
+    fn decode_value_with_b_encoded_word() {
+        let value = b"=?UTF-8?B?4oKsMTAw?=";
+
+        assert_eq!(decode_value(value), "\u{20ac}100");
+    }
+
+    #[cfg(feature = "encoding")]
+    #[test]
+    fn decode_value_with_q_encoded_word() {
+        let value = b"=?UTF-8?Q?=e2=82=ac100?=";
+
+        assert_eq!(decode_value(value), "\u{20ac}100");
+    }
+
+    #[cfg(feature = "encoding")]
+    #[test]
+    fn decode_value_strips_whitespace_between_adjacent_encoded_words() {
+        let value = b"=?UTF-8?Q?Hello,?= =?UTF-8?Q?_world!?=";
+
+        assert_eq!(decode_value(value), "Hello, world!");
+    }
+
+    #[cfg(feature = "encoding")]
+    #[test]
+    fn decode_value_with_rfc8187_ext_value() {
+        let value = b"UTF-8''%e2%82%ac%20rates";
+
+        assert_eq!(decode_value(value), "\u{20ac} rates");
+    }
+
+    #[cfg(feature = "encoding")]
+    #[test]
+    fn decode_value_with_plain_ascii_is_borrowed() {
+        let value = b"text/html";
+
+        let decoded = decode_value(value);
+
+        assert_eq!(decoded, "text/html");
+        assert!(matches!(decoded, std::borrow::Cow::Borrowed(_)));
+    }
 }